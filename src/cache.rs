@@ -0,0 +1,49 @@
+use directories::ProjectDirs;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::fs;
+use std::hash::{Hash, Hasher};
+use std::path::{Path, PathBuf};
+
+/// The metadata we persist per indexed path so a repeat search of the same root can skip
+/// re-stat'ing files that haven't changed.
+#[derive(Serialize, Deserialize, Clone, Copy)]
+pub struct CachedMeta {
+    pub modified: u64, // seconds since the Unix epoch
+    pub size: u64,
+}
+
+pub type IndexCache = HashMap<PathBuf, CachedMeta>;
+
+/// Returns the on-disk cache file for a given scanned root, one file per root.
+fn cache_path_for_root(root: &str) -> Option<PathBuf> {
+    let dirs = ProjectDirs::from("dev", "rust_search", "rust_search")?;
+    let cache_dir = dirs.cache_dir();
+    fs::create_dir_all(cache_dir).ok()?;
+
+    let mut hasher = std::collections::hash_map::DefaultHasher::new();
+    root.hash(&mut hasher);
+    Some(cache_dir.join(format!("index-{:x}.cache", hasher.finish())))
+}
+
+/// Loads the persisted index for `root`, or an empty index if there isn't one yet (or it's
+/// corrupt/unreadable).
+pub fn load(root: &str) -> IndexCache {
+    cache_path_for_root(root)
+        .and_then(|path| fs::read(path).ok())
+        .and_then(|bytes| bincode::deserialize(&bytes).ok())
+        .unwrap_or_default()
+}
+
+/// Persists `cache` for `root`, overwriting any previous index.
+pub fn save(root: &str, cache: &IndexCache) {
+    let Some(path) = cache_path_for_root(root) else { return };
+    if let Ok(bytes) = bincode::serialize(cache) {
+        let _ = fs::write(path, bytes);
+    }
+}
+
+/// Looks up `path` in `cache`, returning its cached size/modified time without touching disk.
+pub fn lookup(cache: &IndexCache, path: &Path) -> Option<CachedMeta> {
+    cache.get(path).copied()
+}
@@ -0,0 +1,83 @@
+use std::fs::File;
+use std::io;
+use std::path::{Path, PathBuf};
+use zip::read::ZipArchive;
+
+/// A file that failed an integrity check, with the kind of check attempted and why it failed.
+pub struct BrokenFileEntry {
+    pub path: PathBuf,
+    pub type_of_file: String,
+    pub error_string: String,
+}
+
+const IMAGE_EXTENSIONS: &[&str] = &["png", "jpg", "jpeg", "gif", "bmp", "ico", "tiff", "webp"];
+
+/// Verifies `path`'s integrity based on its extension, returning a `BrokenFileEntry` if the
+/// file is corrupt/unreadable, or `None` if it checks out (or its type isn't checked at all).
+pub fn check_file(path: &Path) -> Option<BrokenFileEntry> {
+    let extension = path.extension().and_then(|e| e.to_str())?.to_lowercase();
+    if extension == "zip" {
+        check_zip(path)
+    } else if IMAGE_EXTENSIONS.contains(&extension.as_str()) {
+        check_image(path)
+    } else if extension == "pdf" {
+        check_pdf(path)
+    } else {
+        None
+    }
+}
+
+/// Opens the archive and fully decompresses every entry, which forces the CRC-32 stored in
+/// each entry to be checked against its decompressed bytes.
+fn check_zip(path: &Path) -> Option<BrokenFileEntry> {
+    let broken = |error_string: String| {
+        Some(BrokenFileEntry {
+            path: path.to_path_buf(),
+            type_of_file: "zip".to_string(),
+            error_string,
+        })
+    };
+
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(e) => return broken(format!("failed to open file: {}", e)),
+    };
+    let mut archive = match ZipArchive::new(file) {
+        Ok(archive) => archive,
+        Err(e) => return broken(format!("failed to read archive: {}", e)),
+    };
+
+    for i in 0..archive.len() {
+        let mut entry = match archive.by_index(i) {
+            Ok(entry) => entry,
+            Err(e) => return broken(format!("entry {} is unreadable: {}", i, e)),
+        };
+        if let Err(e) = io::copy(&mut entry, &mut io::sink()) {
+            return broken(format!("entry {} ({}) failed to decompress: {}", i, entry.name(), e));
+        }
+    }
+    None
+}
+
+fn check_image(path: &Path) -> Option<BrokenFileEntry> {
+    match image::open(path) {
+        Ok(_) => None,
+        Err(e) => Some(BrokenFileEntry {
+            path: path.to_path_buf(),
+            type_of_file: "image".to_string(),
+            error_string: e.to_string(),
+        }),
+    }
+}
+
+/// Attempts a structural parse of the PDF (xref table, trailer, and object graph).
+fn check_pdf(path: &Path) -> Option<BrokenFileEntry> {
+    match lopdf::Document::load(path) {
+        Ok(_) => None,
+        Err(e) => Some(BrokenFileEntry {
+            path: path.to_path_buf(),
+            type_of_file: "pdf".to_string(),
+            error_string: e.to_string(),
+        }),
+    }
+}
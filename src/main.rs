@@ -1,22 +1,232 @@
+mod cache;
+mod integrity;
+
+use cache::{CachedMeta, IndexCache};
 use eframe::{egui, epi};
+use fuzzy_matcher::skim::SkimMatcherV2;
+use fuzzy_matcher::FuzzyMatcher;
+use integrity::BrokenFileEntry;
 use log::{info, error};
 use native_dialog::FileDialog;
+use notify::{Event, RecommendedWatcher, RecursiveMode, Watcher};
 use rayon::prelude::*;
+use std::collections::{HashMap, HashSet};
 use std::fs::{self, File};
+use std::io::{BufRead, BufReader};
 use std::path::{Path, PathBuf};
 use std::process::Command;
-use std::sync::{Arc, Mutex, atomic::{AtomicUsize, Ordering}};
+use std::sync::mpsc::{channel, RecvTimeoutError};
+use std::sync::{Arc, Mutex, atomic::{AtomicBool, AtomicUsize, Ordering}};
 use std::thread;
-use std::time::{Duration, Instant};
+use std::time::{Duration, Instant, UNIX_EPOCH};
 use tempfile::tempdir;
 use walkdir::{DirEntry, WalkDir};
 use zip::read::ZipArchive;
 
+/// Bursts of filesystem events are coalesced over this window before `results` is updated.
+const WATCH_DEBOUNCE: Duration = Duration::from_millis(300);
+
+/// Content search keeps at most this many line matches per file, so one file can't flood the
+/// results list; matches past the cap are not reported.
+const MAX_LINE_MATCHES_PER_FILE: usize = 50;
+
+/// A single hit, either a matched file name or a matched line inside a file's contents.
+/// `FileName.matched_name` is always a trailing substring of `path`, so the UI can split the
+/// label into a plain prefix and a highlighted `matched_name` using `matched_indices`.
+enum SearchResult {
+    FileName {
+        path: String,
+        zip_path: Option<PathBuf>,
+        is_zip_entry: bool,
+        matched_name: String,
+        score: i64,
+        matched_indices: Vec<usize>,
+        size: Option<u64>,
+        modified: Option<u64>, // seconds since the Unix epoch
+    },
+    LineInFile {
+        path: PathBuf,
+        line_number: usize,
+        line_text: String,
+        score: i64,
+    },
+}
+
+impl SearchResult {
+    fn score(&self) -> i64 {
+        match self {
+            SearchResult::FileName { score, .. } => *score,
+            SearchResult::LineInFile { score, .. } => *score,
+        }
+    }
+
+    fn modified(&self) -> Option<u64> {
+        match self {
+            SearchResult::FileName { modified, .. } => *modified,
+            SearchResult::LineInFile { .. } => None,
+        }
+    }
+
+    fn size(&self) -> Option<u64> {
+        match self {
+            SearchResult::FileName { size, .. } => *size,
+            SearchResult::LineInFile { .. } => None,
+        }
+    }
+
+    /// The real filesystem path this result came from, if any (zip-entry `FileName` results
+    /// have no standalone path and are excluded from filesystem-watch updates).
+    fn path_buf(&self) -> Option<PathBuf> {
+        match self {
+            SearchResult::FileName { path, is_zip_entry: false, .. } => Some(PathBuf::from(path)),
+            SearchResult::FileName { .. } => None,
+            SearchResult::LineInFile { path, .. } => Some(path.clone()),
+        }
+    }
+}
+
+/// Metadata bounds applied to name matches in `search_files`; `None` means "no constraint".
+#[derive(Default, Clone)]
+struct MetadataFilters {
+    min_size: Option<u64>,
+    max_size: Option<u64>,
+    modified_after: Option<u64>,
+    modified_before: Option<u64>,
+    extensions: Option<Vec<String>>,
+}
+
+impl MetadataFilters {
+    /// The bounds checks shared by `fuzzy_match` (walked entries, metadata via `resolve_metadata`)
+    /// and `fuzzy_match_live` (watch-triggered rematches of a single path, no cache involved).
+    fn accepts_metadata(&self, ext: Option<&str>, size: u64, modified: Option<u64>) -> bool {
+        if let Some(min) = self.min_size {
+            if size < min {
+                return false;
+            }
+        }
+        if let Some(max) = self.max_size {
+            if size > max {
+                return false;
+            }
+        }
+        if let Some(after) = self.modified_after {
+            if modified.map_or(true, |m| m < after) {
+                return false;
+            }
+        }
+        if let Some(before) = self.modified_before {
+            if modified.map_or(true, |m| m > before) {
+                return false;
+            }
+        }
+        if let Some(extensions) = &self.extensions {
+            match ext.map(|e| e.to_lowercase()) {
+                Some(ext) if extensions.contains(&ext) => {}
+                _ => return false,
+            }
+        }
+        true
+    }
+}
+
+/// Stats a directory entry directly (always — there are far fewer directories than files, so
+/// this is cheap), records its freshly observed mtime into `dir_mtimes` for its children to
+/// consult, and mirrors the result into `new_cache`.
+fn resolve_dir_metadata(
+    entry: &DirEntry,
+    new_cache: &Arc<Mutex<IndexCache>>,
+    dir_mtimes: &Arc<Mutex<HashMap<PathBuf, u64>>>,
+) -> Option<(u64, Option<u64>)> {
+    let meta = entry.metadata().ok()?;
+    let size = meta.len();
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+    if let Some(modified) = modified {
+        dir_mtimes.lock().unwrap().insert(entry.path().to_path_buf(), modified);
+        new_cache
+            .lock()
+            .unwrap()
+            .insert(entry.path().to_path_buf(), CachedMeta { modified, size });
+    }
+    Some((size, modified))
+}
+
+/// Resolves `entry`'s size/modified time, stat'ing it unless we can prove nothing changed
+/// since `old_cache` was written: if `entry`'s parent directory's freshly observed mtime (in
+/// `dir_mtimes`, populated as each directory is walked) still matches what `old_cache` recorded
+/// for that parent, no entries were added, removed, or renamed there, so the cached size/mtime
+/// for this exact path is trusted without a stat. This is the "expensive work" a repeat search
+/// of an unchanged tree now actually skips. The trade-off: an in-place edit to a file that
+/// doesn't also touch its parent directory's mtime won't be picked up until something else in
+/// that directory changes (or "Rebuild index" is used). Every entry that's actually walked is
+/// (re)written into `new_cache`, so paths that disappear between runs are naturally dropped
+/// from the index instead of lingering forever.
+fn resolve_metadata(
+    entry: &DirEntry,
+    old_cache: &IndexCache,
+    new_cache: &Arc<Mutex<IndexCache>>,
+    dir_mtimes: &Arc<Mutex<HashMap<PathBuf, u64>>>,
+) -> Option<(u64, Option<u64>)> {
+    if entry.file_type().is_dir() {
+        return resolve_dir_metadata(entry, new_cache, dir_mtimes);
+    }
+
+    if let Some(parent) = entry.path().parent() {
+        let parent_fresh_mtime = dir_mtimes.lock().unwrap().get(parent).copied();
+        if let Some(fresh) = parent_fresh_mtime {
+            if let (Some(cached_parent), Some(cached_file)) =
+                (cache::lookup(old_cache, parent), cache::lookup(old_cache, entry.path()))
+            {
+                if cached_parent.modified == fresh {
+                    new_cache.lock().unwrap().insert(entry.path().to_path_buf(), cached_file);
+                    return Some((cached_file.size, Some(cached_file.modified)));
+                }
+            }
+        }
+    }
+
+    let meta = entry.metadata().ok()?;
+    let size = meta.len();
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+    if let Some(modified) = modified {
+        new_cache
+            .lock()
+            .unwrap()
+            .insert(entry.path().to_path_buf(), CachedMeta { modified, size });
+    }
+    Some((size, modified))
+}
+
+/// Which kind of scan the "Go" button runs.
+#[derive(PartialEq)]
+enum ScanMode {
+    NameSearch,
+    IntegrityCheck,
+}
+
 struct FileSearcherApp {
     query: String,
-    results: Arc<Mutex<Vec<(String, Option<PathBuf>, bool)>>>, // (result, zip_path, is_zip_entry)
+    scan_mode: ScanMode,
+    results: Arc<Mutex<Vec<SearchResult>>>,
+    broken_results: Arc<Mutex<Vec<BrokenFileEntry>>>,
     searching: Arc<Mutex<bool>>,
+    stop_flag: Arc<AtomicBool>,
+    watcher: Arc<Mutex<Option<RecommendedWatcher>>>,
     search_directory: String,
+    content_search: bool,
+    min_size: String,
+    max_size: String,
+    modified_after: String,
+    modified_before: String,
+    extensions: String,
+    rebuild_index: bool,
     progress: Arc<Mutex<f32>>,
     total_entries: Arc<AtomicUsize>,
     processed_entries: Arc<AtomicUsize>,
@@ -37,9 +247,20 @@ impl FileSearcherApp {
         env_logger::init();
         Self {
             query: String::new(),
+            scan_mode: ScanMode::NameSearch,
             results: Arc::new(Mutex::new(Vec::new())),
+            broken_results: Arc::new(Mutex::new(Vec::new())),
             searching: Arc::new(Mutex::new(false)),
+            stop_flag: Arc::new(AtomicBool::new(false)),
+            watcher: Arc::new(Mutex::new(None)),
             search_directory: String::new(),
+            content_search: false,
+            min_size: String::new(),
+            max_size: String::new(),
+            modified_after: String::new(),
+            modified_before: String::new(),
+            extensions: String::new(),
+            rebuild_index: false,
             progress: Arc::new(Mutex::new(0.0)),
             total_entries: Arc::new(AtomicUsize::new(0)),
             processed_entries: Arc::new(AtomicUsize::new(0)),
@@ -50,6 +271,25 @@ impl FileSearcherApp {
         }
     }
 
+    /// Parses the filter input fields into a `MetadataFilters`, ignoring blank/unparsable fields.
+    fn build_filters(&self) -> MetadataFilters {
+        MetadataFilters {
+            min_size: parse_size(&self.min_size),
+            max_size: parse_size(&self.max_size),
+            modified_after: parse_date_to_epoch_secs(&self.modified_after),
+            modified_before: parse_date_to_epoch_secs(&self.modified_before),
+            extensions: {
+                let exts: Vec<String> = self
+                    .extensions
+                    .split(',')
+                    .map(|e| e.trim().trim_start_matches('.').to_lowercase())
+                    .filter(|e| !e.is_empty())
+                    .collect();
+                if exts.is_empty() { None } else { Some(exts) }
+            },
+        }
+    }
+
     fn search_files(&self) {
         let query = self.query.clone().to_lowercase();
         let search_directory = if self.search_directory.is_empty() {
@@ -57,8 +297,24 @@ impl FileSearcherApp {
         } else {
             self.search_directory.clone()
         };
+        let content_search = self.content_search;
+        let filters = self.build_filters();
+        let old_cache: IndexCache = if self.rebuild_index {
+            IndexCache::new()
+        } else {
+            cache::load(&search_directory)
+        };
+        // Starts empty rather than seeded from `old_cache`: every entry actually walked this run
+        // gets (re)written into it, so a path that's gone by this run is simply never inserted
+        // and the persisted index can't accumulate metadata for deleted files forever.
+        let new_cache: Arc<Mutex<IndexCache>> = Arc::new(Mutex::new(IndexCache::new()));
+        // Each directory's freshly observed mtime, recorded as it's walked, so `resolve_metadata`
+        // can tell a file's parent is unchanged since `old_cache` without re-stating the file.
+        let dir_mtimes: Arc<Mutex<HashMap<PathBuf, u64>>> = Arc::new(Mutex::new(HashMap::new()));
         let results = Arc::clone(&self.results);
         let searching = Arc::clone(&self.searching);
+        let stop_flag = Arc::clone(&self.stop_flag);
+        let watcher_slot = Arc::clone(&self.watcher);
         let progress = Arc::clone(&self.progress);
         let total_entries = Arc::clone(&self.total_entries);
         let processed_entries = Arc::clone(&self.processed_entries);
@@ -67,9 +323,13 @@ impl FileSearcherApp {
         let show_stats_button = Arc::clone(&self.show_stats_button);
         let show_stats = Arc::clone(&self.show_stats);
 
+        // Dropping the previous watcher unregisters it and lets its debounce thread exit.
+        *watcher_slot.lock().unwrap() = None;
+
         thread::spawn(move || {
             let start_time = Instant::now();
             *searching.lock().unwrap() = true;
+            stop_flag.store(false, Ordering::Relaxed);
             *progress.lock().unwrap() = 0.0;
             results.lock().unwrap().clear();
             total_entries.store(0, Ordering::SeqCst);
@@ -80,25 +340,32 @@ impl FileSearcherApp {
                 .into_iter()
                 .par_bridge()
                 .filter_map(|entry| entry.ok())
-                .map(|_| {
-                    total_entries.fetch_add(1, Ordering::SeqCst) + 1
+                .filter_map(|_| {
+                    if stop_flag.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                    Some(total_entries.fetch_add(1, Ordering::SeqCst) + 1)
                 })
                 .count();
 
             *status_message.lock().unwrap() = "Processing items...".to_string();
 
-            let matched_files: Vec<(String, Option<PathBuf>, bool)> = WalkDir::new(&search_directory)
+            let matcher = SkimMatcherV2::default();
+            let matched_files: Vec<SearchResult> = WalkDir::new(&search_directory)
                 .follow_links(true)
                 .into_iter()
                 .par_bridge()
                 .filter_map(|e| e.ok())
                 .filter_map(|entry| {
-                    if entry.path().extension().and_then(|s| s.to_str()) == Some("zip") {
-                        search_in_zip(&entry.path(), &query, &results);
+                    if stop_flag.load(Ordering::Relaxed) {
+                        return None;
                     }
-                    if is_match(&entry, &query) {
-                        return Some((entry.path().display().to_string(), None::<PathBuf>, false));
+                    if entry.path().extension().and_then(|s| s.to_str()) == Some("zip") {
+                        search_in_zip(&entry.path(), &query, &results, &stop_flag);
+                    } else if content_search && entry.file_type().is_file() {
+                        search_file_contents(&matcher, entry.path(), &query, &results);
                     }
+                    let found = fuzzy_match(&matcher, &entry, &query, &filters, &old_cache, &new_cache, &dir_mtimes);
                     let processed = processed_entries.fetch_add(1, Ordering::SeqCst) + 1;
                     *progress.lock().unwrap() = processed as f32 / total_count as f32;
                     if processed % 1000 == 0 {
@@ -108,12 +375,20 @@ impl FileSearcherApp {
                             total_count
                         );
                     }
-                    None
+                    found
                 })
                 .collect();
 
             let mut all_results = results.lock().unwrap();
             all_results.extend(matched_files);
+            all_results.sort_by(|a, b| {
+                b.score()
+                    .cmp(&a.score())
+                    .then_with(|| b.modified().unwrap_or(0).cmp(&a.modified().unwrap_or(0)))
+                    .then_with(|| b.size().unwrap_or(0).cmp(&a.size().unwrap_or(0)))
+            });
+
+            cache::save(&search_directory, &new_cache.lock().unwrap());
 
             let total_time = start_time.elapsed();
             *search_stats.lock().unwrap() = Some(SearchStats {
@@ -122,31 +397,147 @@ impl FileSearcherApp {
                 total_time,
             });
 
-            info!("Search completed with {} results found.", all_results.len());
-            *searching.lock().unwrap() = false;
+            let stopped = stop_flag.load(Ordering::Relaxed);
+            info!("Search {} with {} results found.", if stopped { "stopped" } else { "completed" }, all_results.len());
             *progress.lock().unwrap() = 1.0;
-            *status_message.lock().unwrap() = "Search completed.".to_string();
+            *status_message.lock().unwrap() = if stopped {
+                "Search stopped.".to_string()
+            } else {
+                "Search completed.".to_string()
+            };
             *show_stats_button.lock().unwrap() = true;
+            drop(all_results);
+
+            // Install this search's watcher *before* clearing `searching` — the UI only lets a
+            // new search start once `searching` goes false, and that new search clears
+            // `watcher_slot` first thing. Flipping `searching` after the install closes the
+            // window where a new search could start, clear the slot, and then have this (now
+            // stale) search overwrite it with a watcher for the old root/query.
+            if !stopped {
+                start_watching(search_directory, query, filters, results, watcher_slot);
+            }
+            *searching.lock().unwrap() = false;
         });
     }
 
-    fn open_file_explorer(&self, path: &str, zip_path: Option<PathBuf>) {
-        if let Some(zip_path) = zip_path {
-            Command::new("explorer")
-                .arg("/select,")
-                .arg(zip_path)
-                .spawn()
-                .expect("Failed to open file explorer");
+    /// Walks `search_directory` checking archive/image/PDF integrity instead of matching names,
+    /// reusing the same progress/status/stop-flag plumbing as `search_files`.
+    fn scan_for_broken_files(&self) {
+        let search_directory = if self.search_directory.is_empty() {
+            "C:/".to_string()
         } else {
-            Command::new("explorer")
-                .arg("/select,")
-                .arg(path)
-                .spawn()
-                .expect("Failed to open file explorer");
+            self.search_directory.clone()
+        };
+        let broken_results = Arc::clone(&self.broken_results);
+        let searching = Arc::clone(&self.searching);
+        let stop_flag = Arc::clone(&self.stop_flag);
+        let progress = Arc::clone(&self.progress);
+        let total_entries = Arc::clone(&self.total_entries);
+        let processed_entries = Arc::clone(&self.processed_entries);
+        let status_message = Arc::clone(&self.status_message);
+
+        // An integrity scan doesn't keep results live, so any watcher from a prior
+        // name/content search should stop rather than going on mutating a result set
+        // that's no longer displayed.
+        *self.watcher.lock().unwrap() = None;
+
+        thread::spawn(move || {
+            *searching.lock().unwrap() = true;
+            stop_flag.store(false, Ordering::Relaxed);
+            *progress.lock().unwrap() = 0.0;
+            broken_results.lock().unwrap().clear();
+            total_entries.store(0, Ordering::SeqCst);
+            processed_entries.store(0, Ordering::SeqCst);
+            *status_message.lock().unwrap() = format!("Counting items in {}...", search_directory);
+
+            let total_count: usize = WalkDir::new(&search_directory)
+                .into_iter()
+                .par_bridge()
+                .filter_map(|entry| entry.ok())
+                .filter_map(|_| {
+                    if stop_flag.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                    Some(total_entries.fetch_add(1, Ordering::SeqCst) + 1)
+                })
+                .count();
+
+            *status_message.lock().unwrap() = "Checking file integrity...".to_string();
+
+            let found: Vec<BrokenFileEntry> = WalkDir::new(&search_directory)
+                .follow_links(true)
+                .into_iter()
+                .par_bridge()
+                .filter_map(|e| e.ok())
+                .filter_map(|entry| {
+                    if stop_flag.load(Ordering::Relaxed) {
+                        return None;
+                    }
+                    let broken = entry.file_type().is_file().then(|| integrity::check_file(entry.path())).flatten();
+                    let processed = processed_entries.fetch_add(1, Ordering::SeqCst) + 1;
+                    *progress.lock().unwrap() = processed as f32 / total_count as f32;
+                    if processed % 1000 == 0 {
+                        *status_message.lock().unwrap() = format!(
+                            "Checked {}/{} items. Please wait...",
+                            processed, total_count
+                        );
+                    }
+                    broken
+                })
+                .collect();
+
+            broken_results.lock().unwrap().extend(found);
+
+            let stopped = stop_flag.load(Ordering::Relaxed);
+            *searching.lock().unwrap() = false;
+            *progress.lock().unwrap() = 1.0;
+            *status_message.lock().unwrap() = if stopped {
+                "Integrity scan stopped.".to_string()
+            } else {
+                "Integrity scan completed.".to_string()
+            };
+        });
+    }
+
+    fn open_file_explorer(&self, result: &SearchResult) {
+        let target: PathBuf = match result {
+            SearchResult::FileName { path, zip_path, .. } => {
+                zip_path.clone().unwrap_or_else(|| PathBuf::from(path))
+            }
+            SearchResult::LineInFile { path, .. } => path.clone(),
+        };
+        Command::new("explorer")
+            .arg("/select,")
+            .arg(target)
+            .spawn()
+            .expect("Failed to open file explorer");
+    }
+
+    fn open_file(&self, result: &SearchResult) {
+        match result {
+            SearchResult::FileName { path, zip_path, is_zip_entry, .. } => {
+                self.open_file_name(path, zip_path.clone(), *is_zip_entry)
+            }
+            SearchResult::LineInFile { path, line_number, .. } => {
+                self.open_file_at_line(path, *line_number)
+            }
         }
     }
 
-    fn open_file(&self, path: &str, zip_path: Option<PathBuf>, is_zip_entry: bool) {
+    /// Opens a file at a specific line using an editor that supports `--goto file:line`,
+    /// falling back to plain Explorer (which has no notion of a line number) on failure.
+    fn open_file_at_line(&self, path: &Path, line_number: usize) {
+        info!("Opening {} at line {}", path.display(), line_number);
+        let goto = format!("{}:{}", path.display(), line_number);
+        if Command::new("code").arg("--goto").arg(&goto).spawn().is_err() {
+            error!("No line-aware editor available, opening {} without a line position", path.display());
+            if let Err(e) = Command::new("explorer").arg(path).spawn() {
+                error!("Failed to open file: {}", e);
+            }
+        }
+    }
+
+    fn open_file_name(&self, path: &str, zip_path: Option<PathBuf>, is_zip_entry: bool) {
         info!("Attempting to open file: path = {}, zip_path = {:?}, is_zip_entry = {}", path, zip_path, is_zip_entry);
         println!("Attempting to open file: path = {}, zip_path = {:?}, is_zip_entry = {}", path, zip_path, is_zip_entry);
         if is_zip_entry {
@@ -250,28 +641,238 @@ impl FileSearcherApp {
     }
 }
 
-fn is_match(entry: &DirEntry, query: &str) -> bool {
-    if let Some(file_name) = entry.file_name().to_str() {
-        file_name.to_lowercase().contains(query)
+/// Fuzzy-matches `entry`'s file name against `query` and the configured metadata filters,
+/// returning a result row with the score and matched char indices (relative to the file name)
+/// on a hit, or `None` if the name doesn't match or the filters reject it.
+///
+/// Resolves (and caches) `entry`'s metadata up front, before even looking at the name — every
+/// walked entry needs to land in `new_cache` so a later search against the same root with a
+/// different query still benefits from the index, not just the entries a prior query matched.
+fn fuzzy_match(
+    matcher: &SkimMatcherV2,
+    entry: &DirEntry,
+    query: &str,
+    filters: &MetadataFilters,
+    old_cache: &IndexCache,
+    new_cache: &Arc<Mutex<IndexCache>>,
+    dir_mtimes: &Arc<Mutex<HashMap<PathBuf, u64>>>,
+) -> Option<SearchResult> {
+    let (size, modified) = resolve_metadata(entry, old_cache, new_cache, dir_mtimes)?;
+    let file_name = entry.file_name().to_str()?;
+    let (score, indices) = matcher.fuzzy_indices(file_name, query)?;
+    let ext = entry.path().extension().and_then(|e| e.to_str());
+    if !filters.accepts_metadata(ext, size, modified) {
+        return None;
+    }
+    Some(SearchResult::FileName {
+        path: entry.path().display().to_string(),
+        zip_path: None,
+        is_zip_entry: false,
+        matched_name: file_name.to_string(),
+        score,
+        matched_indices: indices,
+        size: Some(size),
+        modified,
+    })
+}
+
+/// Re-evaluates a single filesystem-watch path against the live query/filters, mirroring
+/// `fuzzy_match` but working from a bare path (a watch event has no `WalkDir` entry to reuse).
+fn fuzzy_match_live(matcher: &SkimMatcherV2, path: &Path, query: &str, filters: &MetadataFilters) -> Option<SearchResult> {
+    let file_name = path.file_name()?.to_str()?;
+    let (score, indices) = matcher.fuzzy_indices(file_name, query)?;
+    let meta = fs::metadata(path).ok()?;
+    let size = meta.len();
+    let modified = meta
+        .modified()
+        .ok()
+        .and_then(|t| t.duration_since(UNIX_EPOCH).ok())
+        .map(|d| d.as_secs());
+    let ext = path.extension().and_then(|e| e.to_str());
+    if !filters.accepts_metadata(ext, size, modified) {
+        return None;
+    }
+    Some(SearchResult::FileName {
+        path: path.display().to_string(),
+        zip_path: None,
+        is_zip_entry: false,
+        matched_name: file_name.to_string(),
+        score,
+        matched_indices: indices,
+        size: Some(size),
+        modified,
+    })
+}
+
+/// Watches `root` recursively and keeps `results` live: on create/rename the touched path is
+/// re-matched and upserted, on delete (or a rematch miss) any existing entry for it is dropped.
+/// Events are coalesced over `WATCH_DEBOUNCE` so a burst of changes only touches `results` once.
+/// The watcher is stored in `watcher_slot`; dropping it (done when a new name search or integrity
+/// scan starts) stops this function's debounce thread by disconnecting its channel. Editing the
+/// directory field alone does not drop it: the old root stays watched until the next search.
+fn start_watching(
+    root: String,
+    query: String,
+    filters: MetadataFilters,
+    results: Arc<Mutex<Vec<SearchResult>>>,
+    watcher_slot: Arc<Mutex<Option<RecommendedWatcher>>>,
+) {
+    let (tx, rx) = channel::<PathBuf>();
+    let mut watcher = match notify::recommended_watcher(move |res: notify::Result<Event>| {
+        if let Ok(event) = res {
+            for path in event.paths {
+                let _ = tx.send(path);
+            }
+        }
+    }) {
+        Ok(watcher) => watcher,
+        Err(e) => {
+            error!("Failed to start filesystem watcher on {}: {}", root, e);
+            return;
+        }
+    };
+    if let Err(e) = watcher.watch(Path::new(&root), RecursiveMode::Recursive) {
+        error!("Failed to watch {}: {}", root, e);
+        return;
+    }
+    *watcher_slot.lock().unwrap() = Some(watcher);
+
+    thread::spawn(move || {
+        let matcher = SkimMatcherV2::default();
+        loop {
+            let first = match rx.recv() {
+                Ok(path) => path,
+                Err(_) => break,
+            };
+
+            // Wait out the full debounce window after each event, extending the deadline as
+            // more arrive, so a sustained burst (e.g. a multi-file copy) is flushed once instead
+            // of once per event.
+            let mut pending: HashSet<PathBuf> = HashSet::new();
+            pending.insert(first);
+            let mut deadline = Instant::now() + WATCH_DEBOUNCE;
+            let disconnected = loop {
+                match rx.recv_timeout(deadline.saturating_duration_since(Instant::now())) {
+                    Ok(path) => {
+                        pending.insert(path);
+                        deadline = Instant::now() + WATCH_DEBOUNCE;
+                    }
+                    Err(RecvTimeoutError::Timeout) => break false,
+                    Err(RecvTimeoutError::Disconnected) => break true,
+                }
+            };
+
+            let mut all_results = results.lock().unwrap();
+            for path in pending.drain() {
+                all_results.retain(|r| r.path_buf().as_deref() != Some(path.as_path()));
+                if let Some(matched) = fuzzy_match_live(&matcher, &path, &query, &filters) {
+                    all_results.push(matched);
+                }
+            }
+            all_results.sort_by(|a, b| {
+                b.score()
+                    .cmp(&a.score())
+                    .then_with(|| b.modified().unwrap_or(0).cmp(&a.modified().unwrap_or(0)))
+                    .then_with(|| b.size().unwrap_or(0).cmp(&a.size().unwrap_or(0)))
+            });
+            drop(all_results);
+
+            if disconnected {
+                break;
+            }
+        }
+    });
+}
+
+/// Parses a plain byte count (e.g. "104857600") into bytes; blank/unparsable input yields `None`.
+fn parse_size(s: &str) -> Option<u64> {
+    let s = s.trim();
+    if s.is_empty() {
+        None
+    } else {
+        s.parse().ok()
+    }
+}
+
+/// Parses a "YYYY-MM-DD" date into seconds since the Unix epoch (UTC, midnight).
+fn parse_date_to_epoch_secs(s: &str) -> Option<u64> {
+    let s = s.trim();
+    if s.is_empty() {
+        return None;
+    }
+    let parts: Vec<&str> = s.split('-').collect();
+    if parts.len() != 3 {
+        return None;
+    }
+    let year: i64 = parts[0].parse().ok()?;
+    let month: i64 = parts[1].parse().ok()?;
+    let day: i64 = parts[2].parse().ok()?;
+    if !(1..=12).contains(&month) || !(1..=31).contains(&day) {
+        return None;
+    }
+
+    let is_leap = |y: i64| (y % 4 == 0 && y % 100 != 0) || y % 400 == 0;
+    let days_in_month = [31, if is_leap(year) { 29 } else { 28 }, 31, 30, 31, 30, 31, 31, 30, 31, 30, 31];
+
+    let mut days: i64 = 0;
+    if year >= 1970 {
+        for y in 1970..year {
+            days += if is_leap(y) { 366 } else { 365 };
+        }
     } else {
-        false
+        for y in year..1970 {
+            days -= if is_leap(y) { 366 } else { 365 };
+        }
+    }
+    for m in &days_in_month[..(month - 1) as usize] {
+        days += m;
     }
+    days += day - 1;
+
+    Some((days.max(0) * 86_400) as u64)
 }
 
-fn search_in_zip(path: &Path, query: &str, results: &Arc<Mutex<Vec<(String, Option<PathBuf>, bool)>>>) {
+/// Splits `text` into contiguous matched/unmatched runs against `matched_indices` (sorted, as
+/// returned by `fuzzy_indices`), so the UI can render one label per run instead of one per char.
+fn build_highlight_runs(text: &str, matched_indices: &[usize]) -> Vec<(bool, String)> {
+    let mut runs: Vec<(bool, String)> = Vec::new();
+    let mut indices = matched_indices.iter().peekable();
+    for (i, ch) in text.chars().enumerate() {
+        let is_match = indices.peek() == Some(&&i);
+        if is_match {
+            indices.next();
+        }
+        match runs.last_mut() {
+            Some((run_is_match, run)) if *run_is_match == is_match => run.push(ch),
+            _ => runs.push((is_match, ch.to_string())),
+        }
+    }
+    runs
+}
+
+fn search_in_zip(path: &Path, query: &str, results: &Arc<Mutex<Vec<SearchResult>>>, stop_flag: &Arc<AtomicBool>) {
+    let matcher = SkimMatcherV2::default();
     if let Ok(file) = File::open(path) {
         if let Ok(mut archive) = ZipArchive::new(file) {
             for i in 0..archive.len() {
+                if stop_flag.load(Ordering::Relaxed) {
+                    break;
+                }
                 if let Ok(mut file) = archive.by_index(i) {
                     let entry_name = file.name().replace("\\", "/");
                     info!("Checking zip entry: {}", entry_name);
                     println!("Checking zip entry: {}", entry_name);
-                    if entry_name.to_lowercase().contains(query) {
-                        results.lock().unwrap().push((
-                            format!("{}: {}", path.display(), file.name()),
-                            Some(path.to_path_buf()),
-                            true,
-                        ));
+                    if let Some((score, indices)) = matcher.fuzzy_indices(&entry_name, query) {
+                        results.lock().unwrap().push(SearchResult::FileName {
+                            path: format!("{}: {}", path.display(), file.name()),
+                            zip_path: Some(path.to_path_buf()),
+                            is_zip_entry: true,
+                            matched_name: entry_name.clone(),
+                            score,
+                            matched_indices: indices,
+                            size: Some(file.size()),
+                            modified: None,
+                        });
                         info!("Found match in zip: {}", file.name());
                         println!("Found match in zip: {}", file.name());
                     }
@@ -287,6 +888,42 @@ fn search_in_zip(path: &Path, query: &str, results: &Arc<Mutex<Vec<(String, Opti
     }
 }
 
+/// Greps the lines of a regular file for `query`, pushing a `LineInFile` result per hit.
+/// Skips files that look binary (a NUL byte in the first block) and caps matches per file
+/// so one huge generated file can't flood the result list.
+fn search_file_contents(matcher: &SkimMatcherV2, path: &Path, query: &str, results: &Arc<Mutex<Vec<SearchResult>>>) {
+    let file = match File::open(path) {
+        Ok(file) => file,
+        Err(_) => return,
+    };
+    let mut reader = BufReader::new(file);
+    match reader.fill_buf() {
+        Ok(buf) if buf.contains(&0u8) => return,
+        Err(_) => return,
+        _ => {}
+    }
+
+    let mut matches_found = 0;
+    for (index, line) in reader.lines().enumerate() {
+        if matches_found >= MAX_LINE_MATCHES_PER_FILE {
+            break;
+        }
+        let line_text = match line {
+            Ok(line_text) => line_text,
+            Err(_) => break,
+        };
+        if let Some(score) = matcher.fuzzy_match(&line_text, query) {
+            results.lock().unwrap().push(SearchResult::LineInFile {
+                path: path.to_path_buf(),
+                line_number: index + 1,
+                line_text,
+                score,
+            });
+            matches_found += 1;
+        }
+    }
+}
+
 impl epi::App for FileSearcherApp {
     fn setup(&mut self, _ctx: &egui::CtxRef, _frame: &epi::Frame, _storage: Option<&dyn epi::Storage>) {
         let mut style = (*_ctx.style()).clone();
@@ -299,12 +936,25 @@ impl epi::App for FileSearcherApp {
         egui::CentralPanel::default().show(ctx, |ui| {
             ui.heading("File Searcher");
 
+            ui.horizontal(|ui| {
+                ui.radio_value(&mut self.scan_mode, ScanMode::NameSearch, "Name search");
+                ui.radio_value(&mut self.scan_mode, ScanMode::IntegrityCheck, "Broken file scan");
+            });
+
             ui.horizontal(|ui| {
                 ui.label("Search:");
                 ui.text_edit_singleline(&mut self.query);
                 if ui.button("Go").clicked() {
                     if !*self.searching.lock().unwrap() {
-                        self.search_files();
+                        match self.scan_mode {
+                            ScanMode::NameSearch => self.search_files(),
+                            ScanMode::IntegrityCheck => self.scan_for_broken_files(),
+                        }
+                    }
+                }
+                if *self.searching.lock().unwrap() {
+                    if ui.button("Stop").clicked() {
+                        self.stop_flag.store(true, Ordering::Relaxed);
                     }
                 }
             });
@@ -325,6 +975,27 @@ impl epi::App for FileSearcherApp {
                 }
             });
 
+            ui.checkbox(&mut self.content_search, "Search file contents");
+
+            ui.horizontal(|ui| {
+                ui.label("Size (bytes):");
+                ui.text_edit_singleline(&mut self.min_size);
+                ui.label("to");
+                ui.text_edit_singleline(&mut self.max_size);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Modified (YYYY-MM-DD):");
+                ui.text_edit_singleline(&mut self.modified_after);
+                ui.label("to");
+                ui.text_edit_singleline(&mut self.modified_before);
+            });
+            ui.horizontal(|ui| {
+                ui.label("Extensions (comma-separated):");
+                ui.text_edit_singleline(&mut self.extensions);
+            });
+
+            ui.checkbox(&mut self.rebuild_index, "Rebuild index (ignore cache)");
+
             ui.separator();
 
             let status_message = self.status_message.lock().unwrap();
@@ -337,15 +1008,55 @@ impl epi::App for FileSearcherApp {
             }
 
             egui::ScrollArea::vertical().show(ui, |ui| {
+                if self.scan_mode == ScanMode::IntegrityCheck {
+                    let broken_results = self.broken_results.lock().unwrap();
+                    for entry in &*broken_results {
+                        ui.group(|ui| {
+                            ui.label(format!("[{}] {}", entry.type_of_file, entry.path.display()));
+                            ui.colored_label(egui::Color32::from_rgb(220, 80, 80), &entry.error_string);
+                        });
+                        ui.add_space(10.0);
+                    }
+                    return;
+                }
+
                 let results = self.results.lock().unwrap();
-                for (result, zip_path, is_zip_entry) in &*results {
+                for result in &*results {
                     ui.group(|ui| {
-                        ui.label(result);
+                        match result {
+                            SearchResult::FileName { path, matched_name, matched_indices, size, modified, .. } => {
+                                let prefix = &path[..path.len() - matched_name.len()];
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.spacing_mut().item_spacing.x = 0.0;
+                                    if !prefix.is_empty() {
+                                        ui.label(prefix);
+                                    }
+                                    for (is_match, run) in build_highlight_runs(matched_name, matched_indices) {
+                                        if is_match {
+                                            ui.colored_label(egui::Color32::from_rgb(255, 200, 0), run);
+                                        } else {
+                                            ui.label(run);
+                                        }
+                                    }
+                                });
+                                if size.is_some() || modified.is_some() {
+                                    ui.label(format!(
+                                        "{} {}",
+                                        size.map(|s| format!("{} bytes", s)).unwrap_or_default(),
+                                        modified.map(|m| format!("modified {}s since epoch", m)).unwrap_or_default(),
+                                    ));
+                                }
+                            }
+                            SearchResult::LineInFile { path, line_number, line_text, .. } => {
+                                ui.label(format!("{}:{}", path.display(), line_number));
+                                ui.monospace(line_text);
+                            }
+                        }
                         if ui.button("Open Location").clicked() {
-                            self.open_file_explorer(result, zip_path.clone());
+                            self.open_file_explorer(result);
                         }
                         if ui.button("Open").clicked() {
-                            self.open_file(result, zip_path.clone(), *is_zip_entry);
+                            self.open_file(result);
                         }
                     });
                     ui.add_space(10.0);
@@ -382,3 +1093,78 @@ fn main() {
     };
     eframe::run_native(Box::new(app), native_options);
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_date_epoch_is_midnight_1970_01_01() {
+        assert_eq!(parse_date_to_epoch_secs("1970-01-01"), Some(0));
+    }
+
+    #[test]
+    fn parse_date_handles_a_realistic_post_1970_date() {
+        // 2024-03-01 is 54 years, 13 of them leap (1972..=2020 every 4th; 2000 counts, being divisible by 400),
+        // plus Jan (31) and Feb (29, 2024 is a leap year) days into the year.
+        assert_eq!(parse_date_to_epoch_secs("2024-03-01"), Some(1_709_251_200));
+    }
+
+    #[test]
+    fn parse_date_handles_leap_day() {
+        assert_eq!(parse_date_to_epoch_secs("2024-02-29"), Some(1_709_164_800));
+    }
+
+    #[test]
+    fn parse_date_clamps_pre_1970_dates_to_epoch_zero() {
+        // Known limitation: a date before the epoch doesn't error out, it silently clamps.
+        assert_eq!(parse_date_to_epoch_secs("1969-12-31"), Some(0));
+    }
+
+    #[test]
+    fn parse_date_rejects_malformed_input() {
+        assert_eq!(parse_date_to_epoch_secs(""), None);
+        assert_eq!(parse_date_to_epoch_secs("not-a-date"), None);
+        assert_eq!(parse_date_to_epoch_secs("2024-13-01"), None);
+        assert_eq!(parse_date_to_epoch_secs("2024-01-32"), None);
+    }
+
+    #[test]
+    fn highlight_runs_splits_into_contiguous_matched_and_unmatched_spans() {
+        // "rs" matched inside "search_files.rs" at indices 13 and 14.
+        let runs = build_highlight_runs("search_files.rs", &[13, 14]);
+        assert_eq!(
+            runs,
+            vec![
+                (false, "search_files.".to_string()),
+                (true, "rs".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn highlight_runs_handles_no_matches() {
+        let runs = build_highlight_runs("plain.txt", &[]);
+        assert_eq!(runs, vec![(false, "plain.txt".to_string())]);
+    }
+
+    #[test]
+    fn highlight_runs_handles_every_char_matched() {
+        let runs = build_highlight_runs("abc", &[0, 1, 2]);
+        assert_eq!(runs, vec![(true, "abc".to_string())]);
+    }
+
+    #[test]
+    fn highlight_runs_handles_alternating_matches() {
+        let runs = build_highlight_runs("abcd", &[0, 2]);
+        assert_eq!(
+            runs,
+            vec![
+                (true, "a".to_string()),
+                (false, "b".to_string()),
+                (true, "c".to_string()),
+                (false, "d".to_string()),
+            ]
+        );
+    }
+}